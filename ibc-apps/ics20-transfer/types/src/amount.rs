@@ -11,7 +11,7 @@ use super::error::TokenTransferError;
 /// A type for representing token transfer amounts.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
-#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord, Display, From, Into)]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord, Display, From, Into)]
 pub struct Amount(
     #[cfg_attr(feature = "schema", schemars(with = "String"))]
     #[serde(serialize_with = "crate::serializers::serialize")]
@@ -90,6 +90,10 @@ impl From<[u64; 4]> for Amount {
 }
 
 impl Amount {
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
     pub fn checked_add(self, rhs: Self) -> Option<Self> {
         self.0.checked_add(rhs.0).map(Self)
     }
@@ -109,11 +113,22 @@ impl FromStr for Amount {
     type Err = TokenTransferError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(TokenTransferError::EmptyAmount);
+        }
         let amount = U256::from_dec_str(s).map_err(TokenTransferError::InvalidAmount)?;
         Ok(Self(amount))
     }
 }
 
+impl TryFrom<&str> for Amount {
+    type Error = TokenTransferError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::from_str(s)
+    }
+}
+
 impl From<u64> for Amount {
     fn from(v: u64) -> Self {
         Self(v.into())
@@ -126,13 +141,44 @@ where
     D: serde::Deserializer<'de>,
 {
     use serde::Deserialize;
-    U256::from_dec_str(<String>::deserialize(deserializer)?.as_str())
+    Amount::from_str(<String>::deserialize(deserializer)?.as_str())
+        .map(|amount| amount.0)
         .map_err(serde::de::Error::custom)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Amount;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn test_amount_max() {
+        let max = U256::MAX.to_string();
+        let amount = Amount::from_str(&max).expect("U256::MAX is a valid amount");
+
+        assert_eq!(amount, U256::MAX.into());
+        assert_eq!(amount.to_string(), max);
+    }
+
+    #[test]
+    fn test_amount_try_from_str() -> Result<(), TokenTransferError> {
+        assert_eq!(Amount::try_from("100")?, Amount::from(100));
+        assert!(Amount::try_from("1.5").is_err());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::decimal("1.5")]
+    #[case::negative("-1")]
+    #[case::non_numeric("one")]
+    #[case::empty("")]
+    #[case::u256_max_plus_1(
+        "115792089237316195423570985008687907853269984665640564039457584007913129639936"
+    )]
+    fn test_invalid_amount(#[case] amount: &str) {
+        assert!(Amount::from_str(amount).is_err());
+    }
 
     #[cfg(feature = "serde")]
     #[test]
@@ -145,6 +191,12 @@ mod tests {
         assert_eq!(de, value);
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_empty_amount() {
+        assert!(serde_json::from_str::<Amount>("\"\"").is_err());
+    }
+
     #[cfg(feature = "borsh")]
     #[test]
     fn borsh_amount() {
@@ -20,6 +20,28 @@ pub type RawCoin = Coin<String>;
 /// Allowed characters in string representation of a denomination.
 const VALID_DENOM_CHARACTERS: &str = "/:._-";
 
+/// Validates a denomination against the Cosmos SDK rules.
+///
+/// Denominations can be 3 ~ 128 characters long and support letters, followed by either
+/// a letter, a number or a separator ('/', ':', '.', '_' or '-'). See:
+/// <https://github.com/cosmos/cosmos-sdk/blob/v0.47.5/types/coin.go#L838-L840>
+///
+/// It is applied when parsing a [`Coin`] from a string or from its protobuf
+/// representation. Deserializing a [`Coin`] with serde does not apply it.
+pub fn validate_denom(denom: &str) -> Result<(), TokenTransferError> {
+    let mut chars = denom.chars();
+    let is_valid = (3..=128).contains(&denom.len())
+        && chars.next().map_or(false, |x| x.is_ascii_alphabetic())
+        && chars.all(|x| x.is_ascii_alphanumeric() || VALID_DENOM_CHARACTERS.contains(x));
+
+    if !is_valid {
+        return Err(TokenTransferError::InvalidDenom {
+            denom: denom.to_string(),
+        });
+    }
+    Ok(())
+}
+
 /// Coin defines a token with a denomination and an amount.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -48,6 +70,48 @@ where
     }
 }
 
+impl<D: PartialEq + Display> Coin<D> {
+    /// Adds the amount of `rhs` to this coin.
+    ///
+    /// Fails if the denominations differ or the sum overflows.
+    pub fn checked_add(self, rhs: Self) -> Result<Self, TokenTransferError> {
+        self.check_same_denom(&rhs)?;
+        let amount = self
+            .amount
+            .checked_add(rhs.amount)
+            .ok_or(TokenTransferError::AmountOverflow)?;
+        Ok(Self {
+            denom: self.denom,
+            amount,
+        })
+    }
+
+    /// Subtracts the amount of `rhs` from this coin.
+    ///
+    /// Fails if the denominations differ or the difference is negative.
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, TokenTransferError> {
+        self.check_same_denom(&rhs)?;
+        let amount = self
+            .amount
+            .checked_sub(rhs.amount)
+            .ok_or(TokenTransferError::AmountUnderflow)?;
+        Ok(Self {
+            denom: self.denom,
+            amount,
+        })
+    }
+
+    fn check_same_denom(&self, other: &Self) -> Result<(), TokenTransferError> {
+        if self.denom != other.denom {
+            return Err(TokenTransferError::DenomMismatch {
+                expected: self.denom.to_string(),
+                actual: other.denom.to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
 impl<D: FromStr> FromStr for Coin<D>
 where
     D::Err: Into<TokenTransferError>,
@@ -56,30 +120,22 @@ where
 
     #[allow(clippy::assign_op_pattern)]
     fn from_str(coin_str: &str) -> Result<Self, TokenTransferError> {
-        // Denominations can be 3 ~ 128 characters long and support letters, followed by either
-        // a letter, a number or a separator ('/', ':', '.', '_' or '-').
-        // Loosely copy the regex from here:
-        // https://github.com/cosmos/cosmos-sdk/blob/v0.47.5/types/coin.go#L838-L840
-        //
-        // equivalent regex code in rust:
-        // let re = Regex::new(r"^(?<amount>[0-9]+)(?<denom>[a-zA-Z0-9/:._-]+)$")?;
+        // Equivalent regex code in rust, where the denom part is what
+        // `validate_denom` checks:
+        // let re = Regex::new(r"^(?<amount>[0-9]+)(?<denom>[a-zA-Z][a-zA-Z0-9/:._-]{2,127})$")?;
         // let cap = re.captures("123stake")?;
         // let (amount, denom) = (cap.name("amount")?.as_str(), cap.name("denom")?.as_str());
 
         let (amount, denom) = coin_str
-            .chars()
-            .position(|x| !x.is_numeric())
+            .find(|x: char| !x.is_ascii_digit())
             .map(|index| coin_str.split_at(index))
             .filter(|(amount, _)| !amount.is_empty())
-            .filter(|(_, denom)| {
-                denom
-                    .chars()
-                    .all(|x| x.is_alphanumeric() || VALID_DENOM_CHARACTERS.contains(x))
-            })
             .ok_or_else(|| TokenTransferError::InvalidCoin {
                 coin: coin_str.to_string(),
             })?;
 
+        validate_denom(denom)?;
+
         Ok(Coin {
             amount: amount.parse()?,
             denom: denom.parse().map_err(Into::into)?,
@@ -94,6 +150,7 @@ where
     type Error = TokenTransferError;
 
     fn try_from(proto: ProtoCoin) -> Result<Coin<D>, Self::Error> {
+        validate_denom(&proto.denom)?;
         let denom = D::from_str(&proto.denom).map_err(Into::into)?;
         let amount = Amount::from_str(&proto.amount)?;
         Ok(Self { denom, amount })
@@ -139,7 +196,7 @@ mod tests {
         U256::MAX,
         "stake"
     )]
-    #[case::digit_in_denom("1a1", 1, "a1")]
+    #[case::digit_in_denom("1a11", 1, "a11")]
     #[case::chars_in_denom("0x1/:._-", 0, "x1/:._-")]
     #[case::ibc_denom("1234ibc/a0B1C", 1234, "ibc/a0B1C")]
     fn test_parse_raw_coin(
@@ -166,11 +223,172 @@ mod tests {
     )]
     #[case::invalid_char_in_denom("0x!")]
     #[case::blackslash_in_denom("0x1/:.\\_-")]
+    #[case::empty_denom("123")]
+    #[case::empty_amount("stake")]
+    #[case::decimal_amount("1.5stake")]
+    #[case::negative_amount("-1stake")]
+    #[case::non_ascii_digit_amount("\u{0661}abc")]
+    #[case::non_ascii_digit_in_amount("1\u{0661}stake")]
+    #[case::too_short_denom("1a1")]
     #[should_panic]
     fn test_failed_parse_raw_coin(#[case] _raw: RawCoin) {}
 
+    #[test]
+    fn test_non_ascii_digit_amount_is_error() {
+        assert!(matches!(
+            RawCoin::from_str("\u{0661}abc"),
+            Err(TokenTransferError::InvalidCoin { .. })
+        ));
+        assert!(matches!(
+            RawCoin::from_str("1\u{0661}stake"),
+            Err(TokenTransferError::InvalidDenom { .. })
+        ));
+    }
+
+    #[test]
+    fn test_failed_raw_coin_from_proto() {
+        let proto = ProtoCoin {
+            denom: "stake".to_string(),
+            amount: "-100".to_string(),
+        };
+
+        assert!(matches!(
+            RawCoin::try_from(proto),
+            Err(TokenTransferError::InvalidAmount(_))
+        ));
+    }
+
+    #[rstest]
+    #[case::too_short("a1")]
+    #[case::leading_digit("1stake")]
+    #[case::illegal_char("sta!ke")]
+    fn test_failed_raw_coin_from_proto_invalid_denom(#[case] denom: &str) {
+        let proto = ProtoCoin {
+            denom: denom.to_string(),
+            amount: "100".to_string(),
+        };
+
+        assert!(matches!(
+            RawCoin::try_from(proto),
+            Err(TokenTransferError::InvalidDenom { .. })
+        ));
+    }
+
+    #[rstest]
+    #[case::letters("stake")]
+    #[case::separators("a0/:._-")]
+    #[case::ibc_hash("ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2")]
+    #[case::max_len(&"a".repeat(128))]
+    fn test_valid_denom(#[case] denom: &str) {
+        assert!(validate_denom(denom).is_ok());
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::too_short("ab")]
+    #[case::too_long(&"a".repeat(129))]
+    #[case::leading_digit("1stake")]
+    #[case::leading_separator("/stake")]
+    #[case::illegal_char("sta!ke")]
+    #[case::non_ascii("stäke")]
+    fn test_invalid_denom(#[case] denom: &str) {
+        assert!(matches!(
+            validate_denom(denom),
+            Err(TokenTransferError::InvalidDenom { denom: invalid }) if invalid == denom
+        ));
+    }
+
+    #[test]
+    fn test_coin_eq_normalizes_amount() -> Result<(), TokenTransferError> {
+        let coin = RawCoin::from_str("100uatom")?;
+        let proto = ProtoCoin {
+            denom: "uatom".to_string(),
+            amount: "0100".to_string(),
+        };
+
+        assert_eq!(RawCoin::from_str("0100uatom")?, coin);
+        assert_eq!(RawCoin::try_from(proto)?, coin);
+        Ok(())
+    }
+
+    #[test]
+    fn test_coin_ord_by_denom_then_amount() -> Result<(), TokenTransferError> {
+        let mut coins = RawCoin::from_string_list("2uosmo,10uatom,9uatom")?;
+        coins.sort();
+
+        assert_eq!(coins, RawCoin::from_string_list("9uatom,10uatom,2uosmo")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_coin_checked_arithmetic() -> Result<(), TokenTransferError> {
+        let coin = RawCoin::from_str("100stake")?;
+        let other = RawCoin::from_str("30stake")?;
+
+        assert_eq!(
+            coin.clone().checked_add(other.clone())?,
+            RawCoin::from_str("130stake")?
+        );
+        assert_eq!(coin.checked_sub(other)?, RawCoin::from_str("70stake")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_coin_checked_add_overflow() {
+        let max = RawCoin {
+            denom: "stake".to_string(),
+            amount: U256::MAX.into(),
+        };
+        let one = RawCoin {
+            denom: "stake".to_string(),
+            amount: 1u64.into(),
+        };
+
+        assert!(matches!(
+            max.checked_add(one),
+            Err(TokenTransferError::AmountOverflow)
+        ));
+    }
+
+    #[test]
+    fn test_coin_checked_sub_underflow() -> Result<(), TokenTransferError> {
+        let coin = RawCoin::from_str("1stake")?;
+        let other = RawCoin::from_str("2stake")?;
+
+        assert!(matches!(
+            coin.checked_sub(other),
+            Err(TokenTransferError::AmountUnderflow)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_coin_checked_arithmetic_denom_mismatch() -> Result<(), TokenTransferError> {
+        let coin = RawCoin::from_str("1stake")?;
+        let other = RawCoin::from_str("1uatom")?;
+
+        assert!(matches!(
+            coin.clone().checked_add(other.clone()),
+            Err(TokenTransferError::DenomMismatch { .. })
+        ));
+        assert!(matches!(
+            coin.checked_sub(other),
+            Err(TokenTransferError::DenomMismatch { .. })
+        ));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::nat("123stake")]
+    #[case::zero("0stake")]
+    #[case::ibc_denom("1234ibc/a0B1C")]
+    fn test_display_raw_coin(#[case] coin_str: &str) -> Result<(), TokenTransferError> {
+        assert_eq!(RawCoin::from_str(coin_str)?.to_string(), coin_str);
+        Ok(())
+    }
+
     #[rstest]
-    #[case::nomal("123stake,1a1,999den0m", &[(123, "stake"), (1, "a1"), (999, "den0m")])]
+    #[case::nomal("123stake,1a11,999den0m", &[(123, "stake"), (1, "a11"), (999, "den0m")])]
     #[case::tricky("123stake,1a1-999den0m", &[(123, "stake"), (1, "a1-999den0m")])]
     #[case::colon_delimiter("123stake:1a1:999den0m", &[(123, "stake:1a1:999den0m")])]
     #[case::dash_delimiter("123stake-1a1-999den0m", &[(123, "stake-1a1-999den0m")])]
@@ -0,0 +1,334 @@
+//! Defines the `Coins` type; a sorted set of coins with distinct denominations.
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use ibc_core::primitives::prelude::*;
+
+use super::amount::Amount;
+use super::coin::{validate_denom, Coin};
+use super::error::TokenTransferError;
+
+/// A set of coins that upholds the Cosmos SDK `sdk.Coins` invariants.
+///
+/// The coins are sorted by the string representation of their denomination,
+/// each denomination appears at most once, and no coin has a zero amount.
+///
+/// It is serialized as the array of `{denom, amount}` objects the SDK expects.
+/// Deserialization is as strict as the SDK, and rejects arrays that violate
+/// the invariants rather than fixing them up.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Coins<D>(Vec<Coin<D>>);
+
+impl<D: Display> Coins<D> {
+    /// Builds a `Coins` set out of arbitrary coins.
+    ///
+    /// Every denomination must pass [`validate_denom`]. The coins are then
+    /// sorted by denomination, the amounts of coins sharing a denomination
+    /// are summed up, and zero amounts are dropped.
+    pub fn new(mut coins: Vec<Coin<D>>) -> Result<Self, TokenTransferError> {
+        for coin in &coins {
+            validate_denom(&coin.denom.to_string())?;
+        }
+        coins.sort_by_cached_key(|coin| coin.denom.to_string());
+
+        let mut merged: Vec<Coin<D>> = Vec::with_capacity(coins.len());
+        for coin in coins {
+            match merged.last_mut() {
+                Some(last) if last.denom.to_string() == coin.denom.to_string() => {
+                    last.amount = last
+                        .amount
+                        .checked_add(coin.amount)
+                        .ok_or(TokenTransferError::AmountOverflow)?;
+                }
+                _ => merged.push(coin),
+            }
+        }
+        merged.retain(|coin| !coin.amount.is_zero());
+
+        Ok(Self(merged))
+    }
+
+    /// Returns true iff the coins are sorted by denomination, have no
+    /// duplicate denominations and no zero amounts.
+    pub fn is_valid(&self) -> bool {
+        self.0.iter().all(|coin| !coin.amount.is_zero())
+            && self
+                .0
+                .windows(2)
+                .all(|pair| pair[0].denom.to_string() < pair[1].denom.to_string())
+    }
+
+    /// Returns the amount of the given denomination, or zero if it is absent.
+    pub fn amount_of(&self, denom: &str) -> Amount {
+        self.position(denom)
+            .map(|index| self.0[index].amount)
+            .unwrap_or_default()
+    }
+
+    /// Returns true iff the set holds a coin of the given denomination.
+    pub fn contains_denom(&self, denom: &str) -> bool {
+        self.position(denom).is_some()
+    }
+
+    /// Adds `other` to this set, summing up the amounts of shared denominations.
+    pub fn checked_add(self, other: Self) -> Result<Self, TokenTransferError> {
+        let mut coins = self.0;
+        coins.extend(other.0);
+        Self::new(coins)
+    }
+
+    /// Subtracts `other` from this set.
+    ///
+    /// Fails if `other` holds a denomination that is absent from this set or
+    /// an amount that exceeds the one of this set.
+    pub fn checked_sub(self, other: Self) -> Result<Self, TokenTransferError> {
+        let mut coins = self.0;
+        for coin in other.0 {
+            let index = coins
+                .binary_search_by_key(&coin.denom.to_string(), |c| c.denom.to_string())
+                .map_err(|_| TokenTransferError::AmountUnderflow)?;
+            coins[index].amount = coins[index]
+                .amount
+                .checked_sub(coin.amount)
+                .ok_or(TokenTransferError::AmountUnderflow)?;
+        }
+        coins.retain(|coin| !coin.amount.is_zero());
+
+        Ok(Self(coins))
+    }
+
+    fn position(&self, denom: &str) -> Option<usize> {
+        self.0
+            .binary_search_by(|coin| coin.denom.to_string().as_str().cmp(denom))
+            .ok()
+    }
+}
+
+impl<D: Display> TryFrom<Vec<Coin<D>>> for Coins<D> {
+    type Error = TokenTransferError;
+
+    /// Unlike [`Coins::new`], fails instead of sorting or merging the coins.
+    fn try_from(coins: Vec<Coin<D>>) -> Result<Self, Self::Error> {
+        for coin in &coins {
+            validate_denom(&coin.denom.to_string())?;
+        }
+
+        let coins = Self(coins);
+        if !coins.is_valid() {
+            return Err(TokenTransferError::InvalidCoins {
+                coins: coins.to_string(),
+            });
+        }
+        Ok(coins)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, D> serde::Deserialize<'de> for Coins<D>
+where
+    D: serde::Deserialize<'de> + Display,
+{
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: serde::Deserializer<'de>,
+    {
+        let coins = Vec::<Coin<D>>::deserialize(deserializer)?;
+        Self::try_from(coins).map_err(serde::de::Error::custom)
+    }
+}
+
+impl<D: Display> Display for Coins<D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        for (index, coin) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{coin}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<D> Coins<D> {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Coin<D>> {
+        self.0.iter()
+    }
+}
+
+impl<D> AsRef<[Coin<D>]> for Coins<D> {
+    fn as_ref(&self) -> &[Coin<D>] {
+        &self.0
+    }
+}
+
+impl<D> From<Coins<D>> for Vec<Coin<D>> {
+    fn from(coins: Coins<D>) -> Self {
+        coins.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::coin::RawCoin;
+
+    fn coins(coins_str: &str) -> Coins<String> {
+        Coins::new(RawCoin::from_string_list(coins_str).expect("valid coins"))
+            .expect("valid coin set")
+    }
+
+    #[test]
+    fn test_coins_new_upholds_invariants() -> Result<(), TokenTransferError> {
+        let coins = Coins::new(RawCoin::from_string_list(
+            "3uosmo,1uatom,0stake,2uatom,5ibc/27394FB092D2ECCD56123C74F36E4C1F",
+        )?)?;
+
+        assert!(coins.is_valid());
+        assert_eq!(
+            Vec::from(coins),
+            RawCoin::from_string_list("5ibc/27394FB092D2ECCD56123C74F36E4C1F,3uatom,3uosmo")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_coins_new_rejects_invalid_denom() {
+        let coins = vec![RawCoin {
+            denom: "a!".to_string(),
+            amount: 1u64.into(),
+        }];
+
+        assert!(matches!(
+            Coins::new(coins),
+            Err(TokenTransferError::InvalidDenom { .. })
+        ));
+    }
+
+    #[test]
+    fn test_coins_is_valid() {
+        let unsorted = Coins(vec![
+            RawCoin::from_str("1uosmo").expect("valid coin"),
+            RawCoin::from_str("1uatom").expect("valid coin"),
+        ]);
+        let duplicated = Coins(vec![
+            RawCoin::from_str("1uatom").expect("valid coin"),
+            RawCoin::from_str("1uatom").expect("valid coin"),
+        ]);
+        let zero = Coins(vec![RawCoin::from_str("0uatom").expect("valid coin")]);
+
+        assert!(!unsorted.is_valid());
+        assert!(!duplicated.is_valid());
+        assert!(!zero.is_valid());
+        assert!(Coins::<String>::default().is_valid());
+    }
+
+    #[test]
+    fn test_coins_amount_of() {
+        let coins = coins("1uatom,2uosmo,3stake");
+
+        assert_eq!(coins.amount_of("uatom"), 1u64.into());
+        assert_eq!(coins.amount_of("uosmo"), 2u64.into());
+        assert_eq!(coins.amount_of("stake"), 3u64.into());
+        assert!(coins.amount_of("ujuno").is_zero());
+    }
+
+    #[test]
+    fn test_coins_contains_denom() {
+        let coins = coins("1uatom,2uosmo,3stake");
+
+        assert!(coins.contains_denom("uatom"));
+        assert!(coins.contains_denom("stake"));
+        assert!(!coins.contains_denom("ujuno"));
+        assert!(!Coins::<String>::default().contains_denom("uatom"));
+    }
+
+    #[test]
+    fn test_coins_add() -> Result<(), TokenTransferError> {
+        let sum = coins("1uatom,2uosmo").checked_add(coins("3stake,4uosmo"))?;
+
+        assert_eq!(sum, coins("3stake,1uatom,6uosmo"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_coins_sub() -> Result<(), TokenTransferError> {
+        let diff = coins("1uatom,6uosmo").checked_sub(coins("1uatom,4uosmo"))?;
+
+        assert_eq!(diff, coins("2uosmo"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_coins_sub_underflow() {
+        assert!(matches!(
+            coins("1uatom").checked_sub(coins("2uatom")),
+            Err(TokenTransferError::AmountUnderflow)
+        ));
+        assert!(matches!(
+            coins("1uatom").checked_sub(coins("1uosmo")),
+            Err(TokenTransferError::AmountUnderflow)
+        ));
+    }
+
+    #[test]
+    fn test_coins_display() {
+        assert_eq!(coins("2uosmo,1uatom").to_string(), "1uatom,2uosmo");
+        assert_eq!(Coins::<String>::default().to_string(), "");
+    }
+
+    #[test]
+    fn test_coins_try_from() -> Result<(), TokenTransferError> {
+        let sorted = RawCoin::from_string_list("1uatom,2uosmo")?;
+        assert_eq!(Coins::try_from(sorted)?, coins("1uatom,2uosmo"));
+
+        for invalid in ["2uosmo,1uatom", "1uatom,2uatom", "0uatom"] {
+            assert!(matches!(
+                Coins::try_from(RawCoin::from_string_list(invalid)?),
+                Err(TokenTransferError::InvalidCoins { .. })
+            ));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_coins_serde_sdk_format() {
+        let json = r#"[{"denom":"uatom","amount":"2500"},{"denom":"uosmo","amount":"100"}]"#;
+        let fee: Coins<String> = serde_json::from_str(json).expect("can deserialize");
+
+        assert_eq!(fee, coins("2500uatom,100uosmo"));
+        assert_eq!(serde_json::to_string(&fee).expect("can serialize"), json);
+    }
+
+    #[rstest]
+    #[case::unsorted(r#"[{"denom":"uosmo","amount":"1"},{"denom":"uatom","amount":"1"}]"#)]
+    #[case::duplicate(r#"[{"denom":"uatom","amount":"1"},{"denom":"uatom","amount":"1"}]"#)]
+    #[case::zero_amount(r#"[{"denom":"uatom","amount":"0"}]"#)]
+    #[case::invalid_denom(r#"[{"denom":"a","amount":"1"}]"#)]
+    fn test_coins_serde_rejects_invalid(#[case] json: &str) {
+        assert!(serde_json::from_str::<Coins<String>>(json).is_err());
+    }
+
+    #[test]
+    fn test_coins_serde_preserves_order() {
+        let coins = coins("2uosmo,1uatom");
+        let json = serde_json::to_string(&coins).expect("can serialize");
+        let de: Coins<String> = serde_json::from_str(&json).expect("can deserialize");
+
+        assert_eq!(de, coins);
+        assert!(json.find("uatom") < json.find("uosmo"));
+    }
+}
@@ -41,8 +41,16 @@ pub enum TokenTransferError {
     },
     /// trace length must be even but got: `{len}`
     InvalidTraceLength { len: u64 },
+    /// amount is empty
+    EmptyAmount,
     /// invalid amount error: `{0}`
     InvalidAmount(FromDecStrErr),
+    /// mismatched denominations: expected `{expected}`, got `{actual}`
+    DenomMismatch { expected: String, actual: String },
+    /// amount overflow
+    AmountOverflow,
+    /// amount underflow
+    AmountUnderflow,
     /// invalid token
     InvalidToken,
     /// expected `{expect_order}` channel, got `{got_order}`
@@ -73,6 +81,10 @@ pub enum TokenTransferError {
     UnknownMsgType { msg_type: String },
     /// invalid coin string: `{coin}`
     InvalidCoin { coin: String },
+    /// invalid denomination: `{denom}`
+    InvalidDenom { denom: String },
+    /// invalid coins `{coins}`: must be sorted by denomination, without duplicates or zero amounts
+    InvalidCoins { coins: String },
     /// decoding raw bytes as UTF8 string error: `{0}`
     Utf8Decode(Utf8Error),
     /// other error: `{0}`
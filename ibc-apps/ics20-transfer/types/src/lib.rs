@@ -24,6 +24,10 @@ mod coin;
 #[cfg(feature = "serde")]
 pub use coin::*;
 #[cfg(feature = "serde")]
+mod coins;
+#[cfg(feature = "serde")]
+pub use coins::*;
+#[cfg(feature = "serde")]
 mod denom;
 #[cfg(feature = "serde")]
 pub use denom::*;